@@ -21,16 +21,43 @@ struct Node<T> {
 /*
     By implementing IntoIterator for a type, you define how it will be "converted to an iterator". -> emphasis on converted
 */
-pub struct IntoIterator<T>(List<T>); //-> unit struct , commonly used as a wrapper, holds state which in this case is the list
-impl<T> Iterator for IntoIterator<T> {
+pub struct IntoIter<T>(List<T>); //-> tuple struct, commonly used as a wrapper, holds state which in this case is the list
+impl<T> Iterator for IntoIter<T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         self.0.pop()
-        //we are using zero because the List passed to IntoIterator is at index 0
+        //we are using zero because the List passed to IntoIter is at index 0
         // self.0 -> List ---> self.0.pop() == List::pop()
     }
 }
 
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
 pub struct Iter<'a, T> {
     /*
         The basic logic we want is to hold a pointer to the current node we want to yield next.
@@ -73,12 +100,6 @@ impl<T> List<T> {
         }
     }
 
-    //calling into converts this type into an iterator!
-    pub fn into_iter(self) -> IntoIterator<T> {
-        //Since into_iter() takes self by value, using a for loop to iterate over a collection consumes that collection.
-        IntoIterator(self) //-> here we move self into the IntoIterator
-    }
-
     pub fn iter<'a>(&'a self) -> Iter<'a, T> {
         /*
             iter borrows self, we need to ensure self lives for as long as Iter is around, reason for the lifetime.
@@ -213,6 +234,30 @@ mod test {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn into_iterator_trait() {
+        let mut list = List::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut collected = Vec::new();
+        for elem in &list {
+            collected.push(*elem);
+        }
+        assert_eq!(collected, vec![3, 2, 1]);
+
+        for elem in &mut list {
+            *elem *= 10;
+        }
+
+        let mut collected = Vec::new();
+        for elem in list {
+            collected.push(elem);
+        }
+        assert_eq!(collected, vec![30, 20, 10]);
+    }
+
     #[test]
     fn iter_mut() {
         let mut list = List::new();
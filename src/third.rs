@@ -0,0 +1,134 @@
+use std::rc::Rc;
+
+/*
+    prepend and tail don't mutate self, they hand back a whole new SharedList.
+    The new list's head just points at the old list's head (or further down it),
+    so two lists can share a common tail without either of them copying it.
+
+    Box can't do this -- Box means one owner, period. Rc is a box with a
+    refcount bolted on, so several lists are allowed to point at the same node.
+    Tradeoff: everything you get back out is &T, never &mut T, since you can't
+    know who else is looking at the node you'd be mutating.
+*/
+
+pub struct SharedList<T> {
+    head: Link<T>,
+}
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+impl<T> SharedList<T> {
+    pub fn new() -> Self {
+        SharedList { head: None }
+    }
+
+    pub fn prepend(&self, elem: T) -> SharedList<T> {
+        // &self, not self! we're not consuming the old list, other lists might still
+        // be pointing at it. self.head.clone() is just bumping an Rc refcount, not
+        // copying the whole tail -- that's the entire point of this exercise.
+        SharedList {
+            head: Some(Rc::new(Node {
+                elem,
+                next: self.head.clone(),
+            })),
+        }
+    }
+
+    pub fn tail(&self) -> SharedList<T> {
+        // "Give me the list, minus its head." and_then because there might be no
+        // head at all (empty list), in which case the tail is just... also empty.
+        SharedList {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+impl<T> Drop for SharedList<T> {
+    fn drop(&mut self) {
+        /*
+            Same stack-overflow trap as second.rs's recursive-drop-in-disguise: dropping
+            head drops its next, which drops its next... for a long enough list that's a
+            long enough call stack to blow up. So, iterative again.
+
+            But there's a second trap here that second.rs didn't have: this node might
+            not even be ours to destroy. Some other SharedList could share this exact
+            tail. Rc::try_unwrap is the "am I the last owner?" check -- Ok means yes,
+            reclaim it and keep walking; Err means somebody else still has a handle on
+            it, so we just stop and let them deal with it whenever they drop.
+        */
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SharedList;
+
+    #[test]
+    fn basics() {
+        let list = SharedList::new();
+        assert_eq!(list.head(), None);
+
+        let list = list.prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.head(), Some(&3));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&2));
+
+        let list = list.tail();
+        assert_eq!(list.head(), Some(&1));
+
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+
+        // Make sure an empty tail doesn't panic
+        let list = list.tail();
+        assert_eq!(list.head(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list = SharedList::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+}